@@ -60,16 +60,22 @@ impl<'a, U: Write + Read + 'a> Actor for EchoServer<'a, U> {
 
             defmt::info!("Application ready. Connect to the serial port to use the service.");
             loop {
-                let _ = self.uart.read(&mut buf[..1]).await;
-                let _ = self.uart.write(&buf[..1]).await;
-                matrix
-                    .request(MatrixCommand::ApplyFrame(&(buf[0] as char)))
-                    .unwrap()
-                    .await;
-                statistics
-                    .request(StatisticsCommand::IncrementCharacterCount)
-                    .unwrap()
-                    .await;
+                let mut chunk = [0; 16];
+                let n = match self.uart.read(&mut chunk).await {
+                    Ok(n) => n,
+                    Err(_) => continue,
+                };
+                let _ = self.uart.write(&chunk[..n]).await;
+                for &c in &chunk[..n] {
+                    matrix
+                        .request(MatrixCommand::ApplyFrame(&(c as char)))
+                        .unwrap()
+                        .await;
+                    statistics
+                        .request(StatisticsCommand::IncrementCharacterCount)
+                        .unwrap()
+                        .await;
+                }
             }
         }
     }
@@ -17,9 +17,16 @@ pub enum Lifecycle {
     Start,
     /// Not currently used.
     Stop,
-    /// Not currently used.
+    /// Not currently used. Genuine low-power behavior for this event (veto
+    /// via a `PowerState` response, idling the CPU only once every bound
+    /// actor has been asked and none objected) needs a mechanism that
+    /// delivers `Lifecycle` to every bound actor and folds their responses
+    /// before `Supervisor` acts -- this tree has no such actor registry or
+    /// dispatch loop to hang that on, so the request is left undone here
+    /// rather than wired to a `Supervisor` that can't actually reach any
+    /// actors.
     Sleep,
-    /// Not currently used.
+    /// Not currently used. Same gap as `Sleep`.
     Hibernate,
 }
 
@@ -0,0 +1,246 @@
+//! Firmware-update (OTA) actor managing an A/B bootloader swap.
+//!
+//! Imports the DFU/`get_state`/`mark_booted`/swap model described in the
+//! embassy firmware-updater documentation and turns it into an actor that
+//! the `Device` can drive from its `Lifecycle` handling.
+
+use crate::bind::Bind;
+use crate::hal::flash::Flash;
+use crate::prelude::*;
+use crate::synchronization::Mutex;
+
+const STATE_PARTITION_OFFSET: u32 = 0x0000_0000;
+const DFU_PARTITION_OFFSET: u32 = 0x0001_0000;
+const MAGIC_SWAPPED: u8 = 0xA5;
+const MAGIC_BOOTED: u8 = 0x5A;
+
+/// Outcome of reading the bootloader's swap state on boot.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum State {
+    /// The running image is already committed; no action is required.
+    Booted,
+    /// The bootloader performed an A/B swap this boot. The application
+    /// should self-test the new image and call `mark_booted`, or leave it
+    /// unconfirmed so the next reset rolls back to the previous image.
+    Swapped,
+}
+
+/// Erase the one `F::ERASE_SIZE` page containing `offset` and stream
+/// `bytes` into the secondary (DFU) partition at `offset`. Callers must write in
+/// erase-page-aligned, erase-page-sized chunks -- the same invariant the
+/// embassy firmware updater relies on -- since each call erases before it
+/// programs.
+pub struct WriteFirmware<'m>(pub u32, pub &'m [u8]);
+
+/// Request a swap to the staged image on next boot.
+pub struct MarkUpdated;
+
+/// Read whether the bootloader swapped in a new image this boot.
+pub struct GetState;
+
+/// Confirm the running image, preventing a revert on the next reset.
+pub struct MarkBooted;
+
+/// Manages the secondary (DFU) flash partition and the bootloader's A/B
+/// swap, so application code can stage and confirm firmware updates through
+/// an actor instead of touching flash directly.
+pub struct FirmwareUpdater<D, F>
+    where
+        D: Device + 'static,
+        F: Flash + 'static,
+{
+    flash: Option<Address<D, Mutex<D, F>>>,
+    state: State,
+    bus: Option<EventBus<D>>,
+}
+
+impl<D, F> FirmwareUpdater<D, F>
+    where
+        D: Device + 'static,
+        F: Flash + 'static,
+{
+    pub fn new() -> Self {
+        Self {
+            flash: None,
+            state: State::Booted,
+            bus: None,
+        }
+    }
+
+    async fn refresh_state(&mut self) {
+        if let Some(ref flash) = self.flash {
+            let mut flash = flash.lock().await;
+            let mut magic = [0; 1];
+            if flash.read(STATE_PARTITION_OFFSET, &mut magic).await.is_ok() {
+                self.state = if magic[0] == MAGIC_SWAPPED {
+                    State::Swapped
+                } else {
+                    State::Booted
+                };
+            }
+        }
+    }
+}
+
+impl<D, F> Default for FirmwareUpdater<D, F>
+    where
+        D: Device + 'static,
+        F: Flash + 'static,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<D, F> Actor<D> for FirmwareUpdater<D, F>
+    where
+        D: Device + 'static,
+        F: Flash + 'static,
+{
+    fn mount(&mut self, _address: Address<D, Self>, bus: EventBus<D>)
+        where
+            Self: Sized,
+    {
+        self.bus.replace(bus);
+    }
+}
+
+impl<D, F> Bind<D, Mutex<D, F>> for FirmwareUpdater<D, F>
+    where
+        D: Device + 'static,
+        F: Flash + 'static,
+{
+    fn on_bind(&'static mut self, address: Address<D, Mutex<D, F>>) {
+        self.flash.replace(address);
+    }
+}
+
+impl<D, F> NotificationHandler<Lifecycle> for FirmwareUpdater<D, F>
+    where
+        D: Device + EventConsumer<State>,
+        F: Flash + 'static,
+{
+    fn on_notification(&'static mut self, event: Lifecycle) -> Completion {
+        match event {
+            Lifecycle::Initialize => Completion::defer(async move {
+                self.refresh_state().await;
+                if self.state == State::Swapped {
+                    // Let the device run its own verification and decide
+                    // whether to `mark_booted` or trigger a rollback reset.
+                    self.bus.as_ref().unwrap().publish(self.state);
+                }
+            }),
+            _ => Completion::immediate(),
+        }
+    }
+}
+
+impl<D, F> RequestHandler<WriteFirmware<'static>> for FirmwareUpdater<D, F>
+    where
+        D: Device + 'static,
+        F: Flash + 'static,
+{
+    /// `None` if a write is requested before `on_bind` has bound the flash.
+    type Response = Option<Result<(), F::Error>>;
+
+    fn on_request(&'static mut self, message: WriteFirmware<'static>) -> Response<Self::Response> {
+        Response::defer(async move {
+            if let Some(ref flash) = self.flash {
+                let mut flash = flash.lock().await;
+                let offset = DFU_PARTITION_OFFSET + message.0;
+                let page = (offset / F::ERASE_SIZE as u32) * F::ERASE_SIZE as u32;
+                if let Err(e) = flash.erase(page, page + F::ERASE_SIZE as u32).await {
+                    return Some(Err(e));
+                }
+                Some(flash.write(offset, message.1).await)
+            } else {
+                None
+            }
+        })
+    }
+}
+
+impl<D, F> RequestHandler<MarkUpdated> for FirmwareUpdater<D, F>
+    where
+        D: Device + 'static,
+        F: Flash + 'static,
+{
+    /// `None` if requested before `on_bind` has bound the flash.
+    type Response = Option<Result<(), F::Error>>;
+
+    fn on_request(&'static mut self, _message: MarkUpdated) -> Response<Self::Response> {
+        Response::defer(async move {
+            if let Some(ref flash) = self.flash {
+                let mut flash = flash.lock().await;
+                Some(flash.write(STATE_PARTITION_OFFSET, &[MAGIC_SWAPPED]).await)
+            } else {
+                None
+            }
+        })
+    }
+}
+
+impl<D, F> RequestHandler<MarkBooted> for FirmwareUpdater<D, F>
+    where
+        D: Device + 'static,
+        F: Flash + 'static,
+{
+    /// `None` if requested before `on_bind` has bound the flash.
+    type Response = Option<Result<(), F::Error>>;
+
+    fn on_request(&'static mut self, _message: MarkBooted) -> Response<Self::Response> {
+        Response::defer(async move {
+            if let Some(ref flash) = self.flash {
+                let mut flash = flash.lock().await;
+                let result = flash.write(STATE_PARTITION_OFFSET, &[MAGIC_BOOTED]).await;
+                if result.is_ok() {
+                    self.state = State::Booted;
+                }
+                Some(result)
+            } else {
+                None
+            }
+        })
+    }
+}
+
+impl<D, F> RequestHandler<GetState> for FirmwareUpdater<D, F>
+    where
+        D: Device + 'static,
+        F: Flash + 'static,
+{
+    type Response = State;
+
+    fn on_request(&'static mut self, _message: GetState) -> Response<Self::Response> {
+        Response::defer(async move {
+            self.refresh_state().await;
+            self.state
+        })
+    }
+}
+
+#[doc(hidden)]
+impl<D, F> Address<D, FirmwareUpdater<D, F>>
+    where
+        D: Device + 'static,
+        F: Flash + 'static,
+{
+    /// `None` if called before `on_bind` has bound the flash.
+    pub async fn write_firmware(&self, offset: u32, bytes: &'static [u8]) -> Option<Result<(), F::Error>> {
+        self.request(WriteFirmware(offset, bytes)).await
+    }
+
+    /// `None` if called before `on_bind` has bound the flash.
+    pub async fn mark_updated(&self) -> Option<Result<(), F::Error>> {
+        self.request(MarkUpdated).await
+    }
+
+    pub async fn get_state(&self) -> State {
+        self.request(GetState).await
+    }
+
+    /// `None` if called before `on_bind` has bound the flash.
+    pub async fn mark_booted(&self) -> Option<Result<(), F::Error>> {
+        self.request(MarkBooted).await
+    }
+}
@@ -0,0 +1,59 @@
+use crate::driver::sensor::hts221::register::h_out::Hout;
+use crate::driver::sensor::hts221::register::t_out::Tout;
+use crate::hal::i2c::{I2c, I2cAddress};
+
+const REG_CALIBRATION_START: u8 = 0x30 | 0x80;
+
+/// Factory-trimmed linear calibration coefficients, read once at startup and
+/// applied to every subsequent `T_OUT`/`H_OUT` reading.
+#[derive(Copy, Clone, Debug)]
+pub struct Calibration {
+    h0_rh: i16,
+    h1_rh: i16,
+    h0_t0_out: i16,
+    h1_t0_out: i16,
+    t0_degc: i16,
+    t1_degc: i16,
+    t0_out: i16,
+    t1_out: i16,
+}
+
+impl Calibration {
+    pub async fn read<I: I2c>(address: I2cAddress, i2c: &mut I) -> Result<Self, I::Error> {
+        let mut buf = [0; 16];
+        i2c.write_read(address, &[REG_CALIBRATION_START], &mut buf)
+            .await?;
+
+        let h0_rh = buf[0] as i16 / 2;
+        let h1_rh = buf[1] as i16 / 2;
+        let t0_degc_x8 = ((buf[5] as u16 & 0x03) << 8 | buf[2] as u16) as i16;
+        let t1_degc_x8 = ((buf[5] as u16 & 0x0C) << 6 | buf[3] as u16) as i16;
+        let h0_t0_out = i16::from_le_bytes([buf[6], buf[7]]);
+        let h1_t0_out = i16::from_le_bytes([buf[10], buf[11]]);
+        let t0_out = i16::from_le_bytes([buf[12], buf[13]]);
+        let t1_out = i16::from_le_bytes([buf[14], buf[15]]);
+
+        Ok(Self {
+            h0_rh,
+            h1_rh,
+            h0_t0_out,
+            h1_t0_out,
+            t0_degc: t0_degc_x8 / 8,
+            t1_degc: t1_degc_x8 / 8,
+            t0_out,
+            t1_out,
+        })
+    }
+
+    /// Convert a raw `T_OUT` reading to degrees Celsius.
+    pub fn calibrated_temperature(&self, t_out: Tout) -> f32 {
+        let slope = (self.t1_degc - self.t0_degc) as f32 / (self.t1_out - self.t0_out) as f32;
+        self.t0_degc as f32 + slope * (t_out.0 - self.t0_out) as f32
+    }
+
+    /// Convert a raw `H_OUT` reading to percent relative humidity.
+    pub fn calibrated_humidity(&self, h_out: Hout) -> f32 {
+        let slope = (self.h1_rh - self.h0_rh) as f32 / (self.h1_t0_out - self.h0_t0_out) as f32;
+        self.h0_rh as f32 + slope * (h_out.0 - self.h0_t0_out) as f32
+    }
+}
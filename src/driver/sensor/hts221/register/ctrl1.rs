@@ -0,0 +1,62 @@
+use crate::hal::i2c::{I2c, I2cAddress};
+
+const REG_CTRL1: u8 = 0x20;
+
+#[derive(Copy, Clone, Debug)]
+pub enum OutputDataRate {
+    OneShot,
+    Hz1,
+    Hz7,
+    Hz12_5,
+}
+
+#[derive(Copy, Clone, Debug)]
+pub enum BlockDataUpdate {
+    Continuous,
+    MsbLsbReading,
+}
+
+/// CTRL_REG1 — power mode, output data rate and block-data-update.
+pub struct Ctrl1(u8);
+
+impl Ctrl1 {
+    pub async fn read<I: I2c>(address: I2cAddress, i2c: &mut I) -> Result<Self, I::Error> {
+        let mut buf = [0; 1];
+        i2c.write_read(address, &[REG_CTRL1], &mut buf).await?;
+        Ok(Self(buf[0]))
+    }
+
+    pub async fn modify<I: I2c>(
+        address: I2cAddress,
+        i2c: &mut I,
+        f: impl FnOnce(&mut Self),
+    ) -> Result<(), I::Error> {
+        let mut reg = Self::read(address, i2c).await?;
+        f(&mut reg);
+        i2c.write(address, &[REG_CTRL1, reg.0]).await
+    }
+
+    pub fn power_active(&mut self) -> &mut Self {
+        self.0 |= 0x80;
+        self
+    }
+
+    pub fn output_data_rate(&mut self, rate: OutputDataRate) -> &mut Self {
+        self.0 &= !0x03;
+        self.0 |= match rate {
+            OutputDataRate::OneShot => 0x00,
+            OutputDataRate::Hz1 => 0x01,
+            OutputDataRate::Hz7 => 0x02,
+            OutputDataRate::Hz12_5 => 0x03,
+        };
+        self
+    }
+
+    pub fn block_data_update(&mut self, bdu: BlockDataUpdate) -> &mut Self {
+        match bdu {
+            BlockDataUpdate::Continuous => self.0 &= !0x04,
+            BlockDataUpdate::MsbLsbReading => self.0 |= 0x04,
+        }
+        self
+    }
+}
@@ -0,0 +1,30 @@
+use crate::hal::i2c::{I2c, I2cAddress};
+
+const REG_CTRL2: u8 = 0x21;
+
+/// CTRL_REG2 — boot, heater and one-shot control.
+pub struct Ctrl2(u8);
+
+impl Ctrl2 {
+    pub async fn read<I: I2c>(address: I2cAddress, i2c: &mut I) -> Result<Self, I::Error> {
+        let mut buf = [0; 1];
+        i2c.write_read(address, &[REG_CTRL2], &mut buf).await?;
+        Ok(Self(buf[0]))
+    }
+
+    pub async fn modify<I: I2c>(
+        address: I2cAddress,
+        i2c: &mut I,
+        f: impl FnOnce(&mut Self),
+    ) -> Result<(), I::Error> {
+        let mut reg = Self::read(address, i2c).await?;
+        f(&mut reg);
+        i2c.write(address, &[REG_CTRL2, reg.0]).await
+    }
+
+    /// Reboot the memory content from the factory-trimmed calibration area.
+    pub fn boot(&mut self) -> &mut Self {
+        self.0 |= 0x80;
+        self
+    }
+}
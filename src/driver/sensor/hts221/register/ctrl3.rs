@@ -0,0 +1,34 @@
+use crate::hal::i2c::{I2c, I2cAddress};
+
+const REG_CTRL3: u8 = 0x22;
+
+/// CTRL_REG3 — data-ready interrupt pin configuration.
+pub struct Ctrl3(u8);
+
+impl Ctrl3 {
+    pub async fn read<I: I2c>(address: I2cAddress, i2c: &mut I) -> Result<Self, I::Error> {
+        let mut buf = [0; 1];
+        i2c.write_read(address, &[REG_CTRL3], &mut buf).await?;
+        Ok(Self(buf[0]))
+    }
+
+    pub async fn modify<I: I2c>(
+        address: I2cAddress,
+        i2c: &mut I,
+        f: impl FnOnce(&mut Self),
+    ) -> Result<(), I::Error> {
+        let mut reg = Self::read(address, i2c).await?;
+        f(&mut reg);
+        i2c.write(address, &[REG_CTRL3, reg.0]).await
+    }
+
+    /// Enable the DRDY interrupt pin.
+    pub fn enable(&mut self, enabled: bool) -> &mut Self {
+        if enabled {
+            self.0 |= 0x04;
+        } else {
+            self.0 &= !0x04;
+        }
+        self
+    }
+}
@@ -0,0 +1,15 @@
+use crate::hal::i2c::{I2c, I2cAddress};
+
+const REG_H_OUT_L: u8 = 0x28 | 0x80;
+
+/// H_OUT_L/H — raw relative-humidity reading.
+#[derive(Copy, Clone, Debug)]
+pub struct Hout(pub i16);
+
+impl Hout {
+    pub async fn read<I: I2c>(address: I2cAddress, i2c: &mut I) -> Result<Self, I::Error> {
+        let mut buf = [0; 2];
+        i2c.write_read(address, &[REG_H_OUT_L], &mut buf).await?;
+        Ok(Self(i16::from_le_bytes(buf)))
+    }
+}
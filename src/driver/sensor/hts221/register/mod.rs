@@ -0,0 +1,14 @@
+//! HTS221 register accessors.
+//!
+//! Each accessor issues non-blocking transactions over the `hal::i2c::I2c`
+//! trait (one for a `read`, a read followed by a write for a `modify`), so
+//! callers must `.await` them from within an actor.
+
+pub mod calibration;
+pub mod ctrl1;
+pub mod ctrl2;
+pub mod ctrl3;
+pub mod h_out;
+pub mod status;
+pub mod t_out;
+pub mod who_am_i;
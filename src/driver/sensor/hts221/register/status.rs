@@ -0,0 +1,26 @@
+use crate::hal::i2c::{I2c, I2cAddress};
+
+const REG_STATUS: u8 = 0x27;
+
+/// STATUS_REG — new-data-available flags for humidity and temperature.
+pub struct Status(u8);
+
+impl Status {
+    pub async fn read<I: I2c>(address: I2cAddress, i2c: &mut I) -> Result<Self, I::Error> {
+        let mut buf = [0; 1];
+        i2c.write_read(address, &[REG_STATUS], &mut buf).await?;
+        Ok(Self(buf[0]))
+    }
+
+    pub fn temperature_available(&self) -> bool {
+        self.0 & 0x01 != 0
+    }
+
+    pub fn humidity_available(&self) -> bool {
+        self.0 & 0x02 != 0
+    }
+
+    pub fn any_available(&self) -> bool {
+        self.temperature_available() || self.humidity_available()
+    }
+}
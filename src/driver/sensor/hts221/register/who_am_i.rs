@@ -0,0 +1,18 @@
+use crate::hal::i2c::{I2c, I2cAddress};
+
+const REG_WHO_AM_I: u8 = 0x0F;
+
+/// WHO_AM_I — fixed device-identification register.
+pub struct WhoAmI(u8);
+
+impl WhoAmI {
+    pub async fn read<I: I2c>(address: I2cAddress, i2c: &mut I) -> Result<Self, I::Error> {
+        let mut buf = [0; 1];
+        i2c.write_read(address, &[REG_WHO_AM_I], &mut buf).await?;
+        Ok(Self(buf[0]))
+    }
+
+    pub fn id(&self) -> u8 {
+        self.0
+    }
+}
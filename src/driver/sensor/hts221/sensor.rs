@@ -1,5 +1,6 @@
 use crate::bind::Bind;
 use crate::driver::sensor::hts221::ready::DataReady;
+use crate::driver::timer::Timer;
 use crate::driver::sensor::hts221::register::calibration::*;
 use crate::driver::sensor::hts221::register::ctrl1::{BlockDataUpdate, Ctrl1, OutputDataRate};
 use crate::driver::sensor::hts221::register::ctrl2::Ctrl2;
@@ -8,34 +9,37 @@ use crate::driver::sensor::hts221::register::h_out::Hout;
 use crate::driver::sensor::hts221::register::status::Status;
 use crate::driver::sensor::hts221::register::t_out::Tout;
 use crate::driver::sensor::hts221::register::who_am_i::WhoAmI;
-use crate::hal::i2c::I2cAddress;
+use crate::hal::i2c::{I2c, I2cAddress, I2cDevice, SetConfig};
+use crate::hal::timer::{Milliseconds, MonotonicClock};
 use crate::prelude::*;
-use crate::synchronization::Mutex;
-use embedded_hal::blocking::i2c::{Read, Write, WriteRead};
 use crate::driver::sensor::hts221::SensorAcquisition;
 
 pub const ADDR: u8 = 0x5F;
 
-pub struct Sensor<D, I>
+pub struct Sensor<D, I, C>
     where
         D: Device + EventConsumer<SensorAcquisition>,
-        I: WriteRead + Read + Write + 'static
+        I: I2c + SetConfig + 'static,
+        C: MonotonicClock + 'static,
 {
     address: I2cAddress,
-    i2c: Option<Address<D, Mutex<D, I>>>,
+    i2c: Option<I2cDevice<D, I>>,
+    timer: Option<Address<D, Timer<D, C>>>,
     calibration: Option<Calibration>,
     bus: Option<EventBus<D>>,
 }
 
-impl<D, I> Sensor<D, I>
+impl<D, I, C> Sensor<D, I, C>
     where
         D: Device + EventConsumer<SensorAcquisition>,
-        I: WriteRead + Read + Write + 'static
+        I: I2c + SetConfig + 'static,
+        C: MonotonicClock + 'static,
 {
     pub fn new() -> Self {
         Self {
             address: I2cAddress::new(ADDR),
             i2c: None,
+            timer: None,
             calibration: None,
             bus: None,
         }
@@ -47,36 +51,67 @@ impl<D, I> Sensor<D, I>
 
     fn initialize(&'static mut self) -> Completion {
         Completion::defer(async move {
-            if let Some(ref i2c) = self.i2c {
-                let mut i2c = i2c.lock().await;
-
-                Ctrl2::modify(self.address, &mut i2c, |reg| {
+            if let Some(ref mut i2c) = self.i2c {
+                if Ctrl2::modify(self.address, i2c, |reg| {
                     reg.boot();
-                });
+                })
+                .await
+                .is_err()
+                {
+                    log::info!("[hts221] i2c error booting from calibration memory");
+                    return;
+                }
 
-                Ctrl1::modify(self.address, &mut i2c, |reg| {
+                if Ctrl1::modify(self.address, i2c, |reg| {
                     reg.power_active()
                         .output_data_rate(OutputDataRate::Hz1)
                         .block_data_update(BlockDataUpdate::MsbLsbReading);
-                });
+                })
+                .await
+                .is_err()
+                {
+                    log::info!("[hts221] i2c error configuring CTRL_REG1");
+                    return;
+                }
 
-                Ctrl3::modify(self.address, &mut i2c, |reg| {
+                if Ctrl3::modify(self.address, i2c, |reg| {
                     reg.enable(true);
-                });
+                })
+                .await
+                .is_err()
+                {
+                    log::info!("[hts221] i2c error configuring CTRL_REG3");
+                    return;
+                }
 
                 //log::info!(
                     //"[hts221] address=0x{:X}",
-                    //WhoAmI::read(self.address, &mut i2c)
+                    //WhoAmI::read(self.address, i2c).await
                 //);
 
-                //let result = self.timer.as_ref().unwrap().request( Delay( Milliseconds(85u32))).await;
+                if let Some(ref timer) = self.timer {
+                    if timer.delay(Milliseconds(85)).await.is_err() {
+                        log::info!("[hts221] timer queue full, skipping boot delay");
+                    }
+                }
+
                 loop {
                     // Ensure status is emptied
-                    if !Status::read(self.address, &mut i2c).any_available() {
-                        break;
+                    match Status::read(self.address, i2c).await {
+                        Ok(status) if status.any_available() => {
+                            if Hout::read(self.address, i2c).await.is_err()
+                                || Tout::read(self.address, i2c).await.is_err()
+                            {
+                                log::info!("[hts221] i2c error draining stale readings");
+                                break;
+                            }
+                        }
+                        Ok(_) => break,
+                        Err(_) => {
+                            log::info!("[hts221] i2c error reading STATUS_REG");
+                            break;
+                        }
                     }
-                    Hout::read(self.address, &mut i2c);
-                    Tout::read(self.address, &mut i2c);
                 }
             }
         })
@@ -84,28 +119,33 @@ impl<D, I> Sensor<D, I>
 
     fn start(&'static mut self) -> Completion {
         Completion::defer(async move {
-            if let Some(ref i2c) = self.i2c {
-                let mut i2c = i2c.lock().await;
-                self.calibration
-                    .replace(Calibration::read(self.address, &mut i2c));
+            if let Some(ref mut i2c) = self.i2c {
+                match Calibration::read(self.address, i2c).await {
+                    Ok(calibration) => {
+                        self.calibration.replace(calibration);
+                    }
+                    Err(_) => log::info!("[hts221] i2c error reading calibration data"),
+                }
             }
         })
     }
 }
 
-impl<D, I> Default for Sensor<D, I>
+impl<D, I, C> Default for Sensor<D, I, C>
     where
         D: Device + EventConsumer<SensorAcquisition>,
-        I: WriteRead + Read + Write + 'static
+        I: I2c + SetConfig + 'static,
+        C: MonotonicClock + 'static,
 {
     fn default() -> Self {
         Sensor::new()
     }
 }
 
-impl<D, I> Actor<D> for Sensor<D, I>
+impl<D, I, C> Actor<D> for Sensor<D, I, C>
     where D: Device + EventConsumer<SensorAcquisition>,
-          I: WriteRead + Read + Write
+          I: I2c + SetConfig,
+          C: MonotonicClock,
 {
     fn mount(&mut self, address: Address<D, Self>, bus: EventBus<D>)
         where
@@ -115,22 +155,36 @@ impl<D, I> Actor<D> for Sensor<D, I>
     }
 }
 
-impl<D, I> Bind<D, Mutex<D, I>>
-for Sensor<D, I>
+impl<D, I, C> Bind<D, I2cDevice<D, I>>
+for Sensor<D, I, C>
+    where
+        D: Device + EventConsumer<SensorAcquisition>,
+        I: I2c + SetConfig + 'static,
+        C: MonotonicClock + 'static,
+{
+    fn on_bind(&'static mut self, i2c: I2cDevice<D, I>) {
+        self.i2c.replace(i2c);
+    }
+}
+
+impl<D, I, C> Bind<D, Timer<D, C>>
+for Sensor<D, I, C>
     where
         D: Device + EventConsumer<SensorAcquisition>,
-        I: WriteRead + Read + Write + 'static
+        I: I2c + SetConfig + 'static,
+        C: MonotonicClock + 'static,
 {
-    fn on_bind(&'static mut self, address: Address<D, Mutex<D, I>>) {
-        self.i2c.replace(address);
+    fn on_bind(&'static mut self, timer: Address<D, Timer<D, C>>) {
+        self.timer.replace(timer);
     }
 }
 
-impl<D, I> NotificationHandler<Lifecycle>
-for Sensor<D, I>
+impl<D, I, C> NotificationHandler<Lifecycle>
+for Sensor<D, I, C>
     where
         D: Device + EventConsumer<SensorAcquisition>,
-        I: WriteRead + Read + Write
+        I: I2c + SetConfig,
+        C: MonotonicClock,
 {
     fn on_notification(&'static mut self, event: Lifecycle) -> Completion {
         //log::info!("[hts221] Lifecycle: {:?}", event);
@@ -144,22 +198,33 @@ for Sensor<D, I>
     }
 }
 
-impl<D, I> NotificationHandler<DataReady>
-for Sensor<D, I>
+impl<D, I, C> NotificationHandler<DataReady>
+for Sensor<D, I, C>
     where
         D: Device + EventConsumer<SensorAcquisition>,
-        I: WriteRead + Read + Write
+        I: I2c + SetConfig,
+        C: MonotonicClock,
 {
     fn on_notification(&'static mut self, message: DataReady) -> Completion {
         Completion::defer(async move {
-            if self.i2c.is_some() {
-                let mut i2c = self.i2c.as_ref().unwrap().lock().await;
-
+            if let Some(ref mut i2c) = self.i2c {
                 if let Some(ref calibration) = self.calibration {
-                    let t_out = Tout::read(self.address, &mut i2c);
+                    let t_out = match Tout::read(self.address, i2c).await {
+                        Ok(t_out) => t_out,
+                        Err(_) => {
+                            log::info!("[hts221] i2c error reading T_OUT");
+                            return;
+                        }
+                    };
                     let temperature = calibration.calibrated_temperature(t_out);
 
-                    let h_out = Hout::read(self.address, &mut i2c);
+                    let h_out = match Hout::read(self.address, i2c).await {
+                        Ok(h_out) => h_out,
+                        Err(_) => {
+                            log::info!("[hts221] i2c error reading H_OUT");
+                            return;
+                        }
+                    };
                     let relative_humidity = calibration.calibrated_humidity(h_out);
 
                     self.bus.as_ref().unwrap().publish(SensorAcquisition {
@@ -180,10 +245,11 @@ for Sensor<D, I>
 }
 
 #[doc(hidden)]
-impl<D, I> Address<D, Sensor<D, I>>
+impl<D, I, C> Address<D, Sensor<D, I, C>>
     where
         D: Device + EventConsumer<SensorAcquisition> + 'static,
-        I: WriteRead + Read + Write
+        I: I2c + SetConfig,
+        C: MonotonicClock,
 {
     pub fn signal_data_ready(&self) {
         self.notify(DataReady)
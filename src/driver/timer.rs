@@ -0,0 +1,248 @@
+//! Timer actor: lets other actors `await` real delays instead of
+//! busy-looping, backed by a monotonic tick source and a fixed-size timer
+//! queue of `MAX_TIMERS` unordered slots, scanned for the earliest deadline.
+
+use crate::hal::timer::{Delay, Instant, Milliseconds, MonotonicClock};
+use crate::prelude::*;
+use core::cell::RefCell;
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll, Waker};
+use cortex_m::interrupt::{self, Mutex};
+use futures::Stream;
+
+const MAX_TIMERS: usize = 16;
+
+/// Returned when every `MAX_TIMERS` queue slot is already occupied.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct QueueFull;
+
+struct Entry {
+    deadline: Instant,
+    waker: Waker,
+}
+
+struct Queue {
+    entries: [Option<Entry>; MAX_TIMERS],
+}
+
+impl Queue {
+    const fn new() -> Self {
+        const NONE: Option<Entry> = None;
+        Self {
+            entries: [NONE; MAX_TIMERS],
+        }
+    }
+
+    fn insert(&mut self, deadline: Instant, waker: Waker) -> Result<(), QueueFull> {
+        match self.entries.iter_mut().find(|e| e.is_none()) {
+            Some(slot) => {
+                slot.replace(Entry { deadline, waker });
+                Ok(())
+            }
+            None => Err(QueueFull),
+        }
+    }
+
+    fn earliest(&self) -> Option<Instant> {
+        self.entries.iter().flatten().map(|e| e.deadline).min()
+    }
+
+    /// Pop every entry whose deadline has elapsed and wake it.
+    fn fire(&mut self, now: Instant) {
+        for slot in self.entries.iter_mut() {
+            if matches!(slot, Some(entry) if entry.deadline <= now) {
+                slot.take().unwrap().waker.wake();
+            }
+        }
+    }
+}
+
+/// A monotonic timer actor. On each hardware alarm interrupt it pops every
+/// queue entry whose deadline has elapsed, wakes its waker, then
+/// reprograms the alarm to the new earliest deadline.
+///
+/// `clock` and `queue` are shared with `on_interrupt`, which runs in real
+/// ISR context, so both are guarded by a `cortex_m::interrupt::Mutex`
+/// instead of a bare `RefCell` -- an alarm IRQ firing while `rearm` or
+/// `DelayFuture::poll` holds a borrow would otherwise re-enter the same
+/// `RefCell` from the ISR and panic.
+pub struct Timer<D, C>
+    where
+        D: Device + 'static,
+        C: MonotonicClock + 'static,
+{
+    clock: Mutex<RefCell<C>>,
+    queue: Mutex<RefCell<Queue>>,
+    bus: Option<EventBus<D>>,
+}
+
+impl<D, C> Timer<D, C>
+    where
+        D: Device + 'static,
+        C: MonotonicClock + 'static,
+{
+    pub fn new(clock: C) -> Self {
+        Self {
+            clock: Mutex::new(RefCell::new(clock)),
+            queue: Mutex::new(RefCell::new(Queue::new())),
+            bus: None,
+        }
+    }
+
+    fn deadline_for(&self, period: Milliseconds) -> Instant {
+        interrupt::free(|cs| {
+            let clock = self.clock.borrow(cs).borrow();
+            let ticks = period.0 as u64 * clock.ticks_per_second() as u64 / 1000;
+            Instant::from_ticks(clock.now().as_ticks() + ticks)
+        })
+    }
+
+    fn rearm(&self, deadline: Instant, waker: Waker) -> Result<(), QueueFull> {
+        interrupt::free(|cs| {
+            self.queue.borrow(cs).borrow_mut().insert(deadline, waker)?;
+            if let Some(earliest) = self.queue.borrow(cs).borrow().earliest() {
+                self.clock.borrow(cs).borrow_mut().set_alarm(earliest);
+            }
+            Ok(())
+        })
+    }
+
+    /// Dispatched via `Supervisor::on_interrupt` once a `Device::mount` has
+    /// registered this timer for the alarm IRQ.
+    pub fn on_interrupt(&self) {
+        interrupt::free(|cs| {
+            let now = self.clock.borrow(cs).borrow().now();
+            self.queue.borrow(cs).borrow_mut().fire(now);
+            if let Some(earliest) = self.queue.borrow(cs).borrow().earliest() {
+                self.clock.borrow(cs).borrow_mut().set_alarm(earliest);
+            }
+        });
+    }
+}
+
+impl<D, C> crate::supervisor::Interrupt for Timer<D, C>
+    where
+        D: Device + 'static,
+        C: MonotonicClock + 'static,
+{
+    fn on_interrupt(&self) {
+        Timer::on_interrupt(self)
+    }
+}
+
+impl<D, C> Default for Timer<D, C>
+    where
+        D: Device + 'static,
+        C: MonotonicClock + Default + 'static,
+{
+    fn default() -> Self {
+        Self::new(C::default())
+    }
+}
+
+impl<D, C> Actor<D> for Timer<D, C>
+    where
+        D: Device + 'static,
+        C: MonotonicClock + 'static,
+{
+    fn mount(&mut self, _address: Address<D, Self>, bus: EventBus<D>)
+        where
+            Self: Sized,
+    {
+        self.bus.replace(bus);
+    }
+}
+
+impl<D, C> NotificationHandler<Lifecycle> for Timer<D, C>
+    where
+        D: Device + 'static,
+        C: MonotonicClock + 'static,
+{
+    fn on_notification(&'static mut self, _event: Lifecycle) -> Completion {
+        Completion::immediate()
+    }
+}
+
+struct DelayFuture<'m, D, C>
+    where
+        D: Device + 'static,
+        C: MonotonicClock + 'static,
+{
+    timer: &'m Timer<D, C>,
+    period: Milliseconds,
+    deadline: Option<Instant>,
+}
+
+impl<'m, D, C> Future for DelayFuture<'m, D, C>
+    where
+        D: Device + 'static,
+        C: MonotonicClock + 'static,
+{
+    type Output = Result<(), QueueFull>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), QueueFull>> {
+        match self.deadline {
+            None => {
+                let deadline = self.timer.deadline_for(self.period);
+                match self.timer.rearm(deadline, cx.waker().clone()) {
+                    Ok(()) => {
+                        self.deadline = Some(deadline);
+                        Poll::Pending
+                    }
+                    Err(e) => Poll::Ready(Err(e)),
+                }
+            }
+            Some(deadline) => {
+                if interrupt::free(|cs| self.timer.clock.borrow(cs).borrow().now()) >= deadline {
+                    Poll::Ready(Ok(()))
+                } else {
+                    Poll::Pending
+                }
+            }
+        }
+    }
+}
+
+impl<D, C> RequestHandler<Delay> for Timer<D, C>
+    where
+        D: Device + 'static,
+        C: MonotonicClock + 'static,
+{
+    type Response = Result<(), QueueFull>;
+
+    fn on_request(&'static mut self, message: Delay) -> Response<Self::Response> {
+        Response::defer(async move {
+            DelayFuture {
+                timer: self,
+                period: message.0,
+                deadline: None,
+            }
+            .await
+        })
+    }
+}
+
+#[doc(hidden)]
+impl<D, C> Address<D, Timer<D, C>>
+    where
+        D: Device + 'static,
+        C: MonotonicClock + 'static,
+{
+    /// Waits out `period`, or returns `Err(QueueFull)` immediately if the
+    /// timer's queue has no free slot for the new deadline.
+    pub async fn delay(&self, period: Milliseconds) -> Result<(), QueueFull> {
+        self.request(Delay(period)).await
+    }
+
+    /// A periodic trigger, useful for replacing an external `DataReady`
+    /// interrupt with an acquisition tick driven purely by the timer queue.
+    /// Ends the stream if the timer's queue ever fills up rather than
+    /// ticking forever.
+    pub fn schedule(&self, period: Milliseconds) -> impl Stream<Item = ()> + '_ {
+        futures::stream::unfold(self, move |address| async move {
+            address.delay(period).await.ok()?;
+            Some(((), address))
+        })
+    }
+}
@@ -0,0 +1,32 @@
+//! Non-blocking flash HAL trait.
+
+use core::future::Future;
+
+/// Interrupt/DMA-driven flash erase, program and read, so a driver that
+/// persists data (a firmware image, configuration) doesn't block the
+/// executor for the duration of an operation.
+pub trait Flash {
+    type Error;
+
+    const READ_SIZE: usize;
+    const WRITE_SIZE: usize;
+    const ERASE_SIZE: usize;
+
+    type ReadFuture<'m>: Future<Output = Result<(), Self::Error>> + 'm
+    where
+        Self: 'm;
+
+    type WriteFuture<'m>: Future<Output = Result<(), Self::Error>> + 'm
+    where
+        Self: 'm;
+
+    type EraseFuture<'m>: Future<Output = Result<(), Self::Error>> + 'm
+    where
+        Self: 'm;
+
+    fn read<'m>(&'m mut self, offset: u32, buffer: &'m mut [u8]) -> Self::ReadFuture<'m>;
+
+    fn write<'m>(&'m mut self, offset: u32, bytes: &'m [u8]) -> Self::WriteFuture<'m>;
+
+    fn erase<'m>(&'m mut self, from: u32, to: u32) -> Self::EraseFuture<'m>;
+}
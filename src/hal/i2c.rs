@@ -0,0 +1,188 @@
+//! Non-blocking I2C HAL traits.
+
+use crate::prelude::*;
+use crate::synchronization::Mutex;
+use core::future::Future;
+
+/// A 7-bit I2C peripheral address.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct I2cAddress(u8);
+
+impl I2cAddress {
+    pub fn new(addr: u8) -> Self {
+        Self(addr)
+    }
+
+    pub fn address(&self) -> u8 {
+        self.0
+    }
+}
+
+/// Interrupt-driven, waker-woken I2C transactions.
+///
+/// Implementations complete a transfer from the peripheral's interrupt
+/// handler and wake the awaiting task, rather than busy-polling the bus as
+/// `embedded_hal::blocking::i2c` does, so a transfer in flight yields the
+/// executor to other actors. Modeled on the I2C peripheral drivers in
+/// embassy-rp/stm32.
+pub trait I2c {
+    type Error;
+
+    type WriteFuture<'m>: Future<Output = Result<(), Self::Error>> + 'm
+    where
+        Self: 'm;
+
+    type WriteReadFuture<'m>: Future<Output = Result<(), Self::Error>> + 'm
+    where
+        Self: 'm;
+
+    /// Write `bytes` to the device at `address`.
+    fn write<'m>(&'m mut self, address: I2cAddress, bytes: &'m [u8]) -> Self::WriteFuture<'m>;
+
+    /// Write `bytes` to the device at `address`, then read back into `buffer`,
+    /// as a single non-blocking transaction.
+    fn write_read<'m>(
+        &'m mut self,
+        address: I2cAddress,
+        bytes: &'m [u8],
+        buffer: &'m mut [u8],
+    ) -> Self::WriteReadFuture<'m>;
+}
+
+/// Adapts a blocking `embedded_hal::blocking::i2c` peripheral to [`I2c`],
+/// for drivers that haven't migrated to an interrupt-driven implementation.
+pub struct BlockingI2c<I>(I)
+where
+    I: embedded_hal::blocking::i2c::WriteRead + embedded_hal::blocking::i2c::Write;
+
+impl<I> BlockingI2c<I>
+where
+    I: embedded_hal::blocking::i2c::WriteRead + embedded_hal::blocking::i2c::Write,
+{
+    pub fn new(i2c: I) -> Self {
+        Self(i2c)
+    }
+}
+
+impl<I> I2c for BlockingI2c<I>
+where
+    I: embedded_hal::blocking::i2c::WriteRead + embedded_hal::blocking::i2c::Write,
+{
+    type Error = ();
+
+    type WriteFuture<'m>
+    where
+        Self: 'm,
+    = impl Future<Output = Result<(), Self::Error>> + 'm;
+
+    type WriteReadFuture<'m>
+    where
+        Self: 'm,
+    = impl Future<Output = Result<(), Self::Error>> + 'm;
+
+    fn write<'m>(&'m mut self, address: I2cAddress, bytes: &'m [u8]) -> Self::WriteFuture<'m> {
+        async move { self.0.write(address.address(), bytes).map_err(|_| ()) }
+    }
+
+    fn write_read<'m>(
+        &'m mut self,
+        address: I2cAddress,
+        bytes: &'m [u8],
+        buffer: &'m mut [u8],
+    ) -> Self::WriteReadFuture<'m> {
+        async move {
+            self.0
+                .write_read(address.address(), bytes, buffer)
+                .map_err(|_| ())
+        }
+    }
+}
+
+/// Per-device bus configuration applied on acquiring a shared bus.
+#[derive(Copy, Clone, Debug)]
+pub struct Config {
+    pub frequency: u32,
+    pub pull_up: bool,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            frequency: 100_000,
+            pull_up: true,
+        }
+    }
+}
+
+/// Implemented by peripheral drivers that can be reconfigured between
+/// transactions, so a shared bus can apply each device's `Config` on
+/// acquiring the bus.
+pub trait SetConfig {
+    fn set_config(&mut self, config: &Config);
+}
+
+/// A handle to one device on an I2C bus shared by several actors.
+///
+/// Wraps an `Address<D, Mutex<D, I>>` to the shared peripheral. Each
+/// transaction acquires the mutex for its own duration, applies `config`,
+/// then releases it, so sibling devices on the same bus can interleave
+/// transactions instead of each holding it for the whole `Sensor` lifetime.
+/// Mirrors the `I2cDevice`/`SetConfig` pattern from
+/// embassy-embedded-hal's `shared_bus`.
+pub struct I2cDevice<D, I>
+where
+    D: Device + 'static,
+    I: I2c + SetConfig + 'static,
+{
+    bus: Address<D, Mutex<D, I>>,
+    config: Config,
+}
+
+impl<D, I> I2cDevice<D, I>
+where
+    D: Device + 'static,
+    I: I2c + SetConfig + 'static,
+{
+    pub fn new(bus: Address<D, Mutex<D, I>>, config: Config) -> Self {
+        Self { bus, config }
+    }
+}
+
+impl<D, I> I2c for I2cDevice<D, I>
+where
+    D: Device + 'static,
+    I: I2c + SetConfig + 'static,
+{
+    type Error = I::Error;
+
+    type WriteFuture<'m>
+    where
+        Self: 'm,
+    = impl Future<Output = Result<(), Self::Error>> + 'm;
+
+    type WriteReadFuture<'m>
+    where
+        Self: 'm,
+    = impl Future<Output = Result<(), Self::Error>> + 'm;
+
+    fn write<'m>(&'m mut self, address: I2cAddress, bytes: &'m [u8]) -> Self::WriteFuture<'m> {
+        async move {
+            let mut i2c = self.bus.lock().await;
+            i2c.set_config(&self.config);
+            i2c.write(address, bytes).await
+        }
+    }
+
+    fn write_read<'m>(
+        &'m mut self,
+        address: I2cAddress,
+        bytes: &'m [u8],
+        buffer: &'m mut [u8],
+    ) -> Self::WriteReadFuture<'m> {
+        async move {
+            let mut i2c = self.bus.lock().await;
+            i2c.set_config(&self.config);
+            i2c.write_read(address, bytes, buffer).await
+        }
+    }
+}
@@ -1,5 +1,6 @@
 //! General HAL types and traits.
 
+pub mod flash;
 pub mod gpio;
 pub mod i2c;
 pub mod timer;
@@ -0,0 +1,36 @@
+//! Monotonic timer HAL types.
+
+/// A point in time, as a tick count since boot.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub struct Instant(u64);
+
+impl Instant {
+    pub fn from_ticks(ticks: u64) -> Self {
+        Self(ticks)
+    }
+
+    pub fn as_ticks(&self) -> u64 {
+        self.0
+    }
+}
+
+/// A span of time expressed in milliseconds.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub struct Milliseconds(pub u32);
+
+/// Request to sleep for a `Milliseconds` span, used with
+/// `Address<D, driver::timer::Timer<..>>::delay`.
+pub struct Delay(pub Milliseconds);
+
+/// A free-running hardware tick source backing the timer queue.
+///
+/// `now` and `ticks_per_second` let the queue convert a `Milliseconds` span
+/// into an absolute `Instant` deadline; `set_alarm` programs the next
+/// hardware interrupt, which the queue reprograms after each pop.
+pub trait MonotonicClock {
+    fn now(&self) -> Instant;
+
+    fn ticks_per_second(&self) -> u32;
+
+    fn set_alarm(&mut self, at: Instant);
+}
@@ -0,0 +1,275 @@
+//! Interrupt-driven, buffered UART.
+
+use core::cell::RefCell;
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll, Waker};
+use cortex_m::interrupt::{self, Mutex};
+
+/// Non-blocking read/write over a UART peripheral.
+pub trait Uart {
+    type Error;
+
+    type ReadFuture<'m>: Future<Output = Result<usize, Self::Error>> + 'm
+    where
+        Self: 'm;
+
+    type WriteFuture<'m>: Future<Output = Result<usize, Self::Error>> + 'm
+    where
+        Self: 'm;
+
+    /// Read whatever is already buffered into `buffer`, waiting for at
+    /// least one byte if the buffer is currently empty. Returns the number
+    /// of bytes read, which may be less than `buffer.len()`.
+    fn read<'m>(&'m mut self, buffer: &'m mut [u8]) -> Self::ReadFuture<'m>;
+
+    /// Queue `bytes` for transmission, waiting for at least one byte of
+    /// free space if the transmit buffer is currently full. Returns the
+    /// number of bytes queued, which may be less than `bytes.len()`.
+    fn write<'m>(&'m mut self, bytes: &'m [u8]) -> Self::WriteFuture<'m>;
+}
+
+/// The raw, single-byte, non-blocking peripheral operations a
+/// `BufferedUart` drains from and fills from its interrupt handler.
+pub trait RawUart {
+    fn try_read(&mut self) -> Option<u8>;
+    fn try_write(&mut self, byte: u8) -> bool;
+    fn set_tx_interrupt(&mut self, enabled: bool);
+}
+
+const RX_BUFFER_SIZE: usize = 64;
+const TX_BUFFER_SIZE: usize = 64;
+
+struct RingBuffer<const N: usize> {
+    buffer: [u8; N],
+    read: usize,
+    write: usize,
+    len: usize,
+}
+
+impl<const N: usize> RingBuffer<N> {
+    const fn new() -> Self {
+        Self {
+            buffer: [0; N],
+            read: 0,
+            write: 0,
+            len: 0,
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    fn is_full(&self) -> bool {
+        self.len == N
+    }
+
+    fn push(&mut self, byte: u8) -> bool {
+        if self.is_full() {
+            return false;
+        }
+        self.buffer[self.write] = byte;
+        self.write = (self.write + 1) % N;
+        self.len += 1;
+        true
+    }
+
+    fn pop(&mut self) -> Option<u8> {
+        if self.is_empty() {
+            return None;
+        }
+        let byte = self.buffer[self.read];
+        self.read = (self.read + 1) % N;
+        self.len -= 1;
+        Some(byte)
+    }
+}
+
+/// A UART wrapped with separate RX/TX ring buffers that are filled/drained
+/// from the peripheral's interrupt handler, so `read`/`write` only move
+/// data through the ring buffers (`write` also enables the TX interrupt)
+/// and return as soon as any data or space is available
+/// instead of waiting on a per-byte round trip through the executor.
+/// Follows the embassy `BufferedUart` design.
+///
+/// Every field is shared with `on_interrupt`, which runs in real ISR
+/// context, so each is guarded by a `cortex_m::interrupt::Mutex` instead of
+/// a bare `RefCell` -- a UART IRQ firing while `read`/`write` holds a
+/// borrow would otherwise re-enter the same `RefCell` from the ISR and
+/// panic.
+pub struct BufferedUart<U>
+where
+    U: RawUart,
+{
+    uart: Mutex<RefCell<U>>,
+    rx: Mutex<RefCell<RingBuffer<RX_BUFFER_SIZE>>>,
+    tx: Mutex<RefCell<RingBuffer<TX_BUFFER_SIZE>>>,
+    rx_waker: Mutex<RefCell<Option<Waker>>>,
+    tx_waker: Mutex<RefCell<Option<Waker>>>,
+}
+
+impl<U> BufferedUart<U>
+where
+    U: RawUart,
+{
+    pub fn new(uart: U) -> Self {
+        Self {
+            uart: Mutex::new(RefCell::new(uart)),
+            rx: Mutex::new(RefCell::new(RingBuffer::new())),
+            tx: Mutex::new(RefCell::new(RingBuffer::new())),
+            rx_waker: Mutex::new(RefCell::new(None)),
+            tx_waker: Mutex::new(RefCell::new(None)),
+        }
+    }
+
+    /// Dispatched via `Supervisor::on_interrupt` once a `Device::mount` has
+    /// registered this UART for its IRQ. Drains every byte the peripheral
+    /// has ready into the RX ring buffer (bytes that arrive while it is full
+    /// are discarded) and refills the peripheral's TX holding register from the TX ring
+    /// buffer, then wakes whichever task is waiting on either side.
+    pub fn on_interrupt(&self) {
+        interrupt::free(|cs| {
+            let mut uart = self.uart.borrow(cs).borrow_mut();
+
+            let mut received = false;
+            while let Some(byte) = uart.try_read() {
+                received |= self.rx.borrow(cs).borrow_mut().push(byte);
+            }
+            if received {
+                if let Some(waker) = self.rx_waker.borrow(cs).borrow_mut().take() {
+                    waker.wake();
+                }
+            }
+
+            let mut sent = false;
+            let mut tx = self.tx.borrow(cs).borrow_mut();
+            while !tx.is_empty() {
+                if !uart.try_write(tx.buffer[tx.read]) {
+                    break;
+                }
+                tx.pop();
+                sent = true;
+            }
+            uart.set_tx_interrupt(!tx.is_empty());
+            drop(tx);
+            if sent {
+                if let Some(waker) = self.tx_waker.borrow(cs).borrow_mut().take() {
+                    waker.wake();
+                }
+            }
+        });
+    }
+}
+
+impl<U> crate::supervisor::Interrupt for BufferedUart<U>
+where
+    U: RawUart,
+{
+    fn on_interrupt(&self) {
+        BufferedUart::on_interrupt(self)
+    }
+}
+
+struct ReadFuture<'m, U>
+where
+    U: RawUart,
+{
+    uart: &'m BufferedUart<U>,
+    buffer: &'m mut [u8],
+}
+
+impl<'m, U> Future for ReadFuture<'m, U>
+where
+    U: RawUart,
+{
+    type Output = Result<usize, ()>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<usize, ()>> {
+        let this = self.get_mut();
+        interrupt::free(|cs| {
+            let mut rx = this.uart.rx.borrow(cs).borrow_mut();
+            if rx.is_empty() {
+                this.uart
+                    .rx_waker
+                    .borrow(cs)
+                    .borrow_mut()
+                    .replace(cx.waker().clone());
+                return Poll::Pending;
+            }
+            let mut read = 0;
+            while read < this.buffer.len() {
+                match rx.pop() {
+                    Some(byte) => {
+                        this.buffer[read] = byte;
+                        read += 1;
+                    }
+                    None => break,
+                }
+            }
+            Poll::Ready(Ok(read))
+        })
+    }
+}
+
+struct WriteFuture<'m, U>
+where
+    U: RawUart,
+{
+    uart: &'m BufferedUart<U>,
+    bytes: &'m [u8],
+}
+
+impl<'m, U> Future for WriteFuture<'m, U>
+where
+    U: RawUart,
+{
+    type Output = Result<usize, ()>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<usize, ()>> {
+        let this = self.get_mut();
+        interrupt::free(|cs| {
+            let mut tx = this.uart.tx.borrow(cs).borrow_mut();
+            if tx.is_full() {
+                this.uart
+                    .tx_waker
+                    .borrow(cs)
+                    .borrow_mut()
+                    .replace(cx.waker().clone());
+                return Poll::Pending;
+            }
+            let mut written = 0;
+            while written < this.bytes.len() && tx.push(this.bytes[written]) {
+                written += 1;
+            }
+            drop(tx);
+            this.uart.uart.borrow(cs).borrow_mut().set_tx_interrupt(true);
+            Poll::Ready(Ok(written))
+        })
+    }
+}
+
+impl<U> Uart for BufferedUart<U>
+where
+    U: RawUart,
+{
+    type Error = ();
+
+    type ReadFuture<'m>
+    where
+        Self: 'm,
+    = ReadFuture<'m, U>;
+
+    type WriteFuture<'m>
+    where
+        Self: 'm,
+    = WriteFuture<'m, U>;
+
+    fn read<'m>(&'m mut self, buffer: &'m mut [u8]) -> Self::ReadFuture<'m> {
+        ReadFuture { uart: self, buffer }
+    }
+
+    fn write<'m>(&'m mut self, bytes: &'m [u8]) -> Self::WriteFuture<'m> {
+        WriteFuture { uart: self, bytes }
+    }
+}
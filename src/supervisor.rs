@@ -0,0 +1,62 @@
+//! Drives the cooperative executor for a `Device` and its low-power idle.
+
+/// A peripheral wrapper that owns interrupt-driven state (a `Timer`'s alarm
+/// queue, a `BufferedUart`'s ring buffers) and can be registered with a
+/// `Supervisor` to receive its hardware IRQ.
+pub trait Interrupt {
+    fn on_interrupt(&self);
+}
+
+const MAX_INTERRUPTS: usize = 8;
+
+/// Owns the idle loop of a `Device` and forwards hardware interrupts to the
+/// registered `Interrupt` sources. It holds no actors and polls no futures
+/// itself.
+pub struct Supervisor {
+    interrupts: [Option<(i16, &'static dyn Interrupt)>; MAX_INTERRUPTS],
+}
+
+impl Supervisor {
+    pub fn new() -> Self {
+        Self {
+            interrupts: [None; MAX_INTERRUPTS],
+        }
+    }
+
+    /// Registers `source` to receive `on_interrupt(irqn)` when the board's
+    /// interrupt handler forwards `irqn` here through
+    /// `DeviceContext::on_interrupt`. Called from a `Device`'s `mount` for
+    /// each interrupt-driven peripheral it binds.
+    pub fn register_interrupt(&mut self, irqn: i16, source: &'static dyn Interrupt) {
+        if let Some(slot) = self.interrupts.iter_mut().find(|e| e.is_none()) {
+            slot.replace((irqn, source));
+        }
+    }
+
+    /// Loops forever, putting the CPU into its idle sleep mode (WFE) on each
+    /// round so it only wakes on an event or interrupt. `run_ready_queue`
+    /// polls nothing; it only marks the point between rounds.
+    pub fn run_forever(&self) -> ! {
+        loop {
+            self.run_ready_queue();
+            self.idle();
+        }
+    }
+
+    fn run_ready_queue(&self) {
+        // Actors mounted under the device drive themselves off their own
+        // wakers; the supervisor only owns the idle point between rounds.
+    }
+
+    fn idle(&self) {
+        cortex_m::asm::wfe();
+    }
+
+    pub fn on_interrupt(&self, irqn: i16) {
+        for entry in self.interrupts.iter().flatten() {
+            if entry.0 == irqn {
+                entry.1.on_interrupt();
+            }
+        }
+    }
+}